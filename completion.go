@@ -0,0 +1,106 @@
+package main
+
+import (
+	"fmt"
+
+	"github.com/urfave/cli/v2"
+)
+
+const bashCompletionTemplate = `#! /bin/bash
+
+# Minimal version of _init_completion for shells without it (e.g. macOS bash 3.2),
+# reading COMP_WORDS directly when the bash-completion package isn't installed.
+_%[1]s_init_completion() {
+  COMPREPLY=()
+  if declare -F _get_comp_words_by_ref >/dev/null 2>&1; then
+    _get_comp_words_by_ref "$@" cur prev words cword
+  else
+    cur="${COMP_WORDS[COMP_CWORD]}"
+    prev="${COMP_WORDS[COMP_CWORD-1]}"
+    words=("${COMP_WORDS[@]}")
+    cword=$COMP_CWORD
+  fi
+}
+
+_%[1]s_bash_autocomplete() {
+  if [[ "${COMP_WORDS[0]}" != "source" ]]; then
+    local cur prev opts words cword requestComp
+    COMPREPLY=()
+    cur="${COMP_WORDS[COMP_CWORD]}"
+    if declare -F _init_completion >/dev/null 2>&1; then
+      _init_completion -n "=:" || return
+    else
+      _%[1]s_init_completion -n "=:" || return
+    fi
+    words=("${words[@]:0:$cword}")
+    if [[ "$cur" == "-"* ]]; then
+      requestComp="${words[*]} ${cur} --generate-bash-completion"
+    else
+      requestComp="${words[*]} --generate-bash-completion"
+    fi
+    opts=$(eval "${requestComp}" 2>/dev/null)
+    COMPREPLY=($(compgen -W "${opts}" -- "${cur}"))
+    return 0
+  fi
+}
+
+complete -o bashdefault -o default -o nospace -F _%[1]s_bash_autocomplete %[1]s
+`
+
+const zshCompletionTemplate = `#compdef %[1]s
+
+_%[1]s_zsh_autocomplete() {
+  local -a opts
+  local cur
+  cur=${words[-1]}
+  if [[ "$cur" == "-"* ]]; then
+    opts=("${(@f)$(${words[@]:0:#words[@]-1} ${cur} --generate-bash-completion)}")
+  else
+    opts=("${(@f)$(${words[@]:0:#words[@]-1} --generate-bash-completion)}")
+  fi
+
+  if [[ "${opts[1]}" != "" ]]; then
+    _describe 'values' opts
+  else
+    _files
+  fi
+}
+
+if [[ $zsh_eval_context[-1] == loadautofunc ]]; then
+  _%[1]s_zsh_autocomplete "$@"
+else
+  compdef _%[1]s_zsh_autocomplete %[1]s
+fi
+`
+
+const powershellCompletionTemplate = `Register-ArgumentCompleter -Native -CommandName '%[1]s' -ScriptBlock {
+  param($wordToComplete, $commandAst, $cursorPosition)
+  $line = $commandAst.Extent.Text
+  $pos = $cursorPosition - $commandAst.Extent.StartOffset
+  if ($line.Length -gt $pos) {
+    $line = $line.Substring(0, $pos)
+  }
+  if ($wordToComplete -ne '' -and -not $wordToComplete.StartsWith('-')) {
+    $line = $line.Substring(0, $line.Length - $wordToComplete.Length)
+  }
+  $line = $line.TrimEnd()
+  Invoke-Expression "$line --generate-bash-completion" | Where-Object { $_ -like "$wordToComplete*" } | ForEach-Object {
+    [System.Management.Automation.CompletionResult]::new($_, $_, 'ParameterValue', $_)
+  }
+}
+`
+
+// completionScript returns the completion script of the given shell for the app
+func completionScript(app *cli.App, shell string) (string, error) {
+	switch shell {
+	case "bash":
+		return fmt.Sprintf(bashCompletionTemplate, app.Name), nil
+	case "zsh":
+		return fmt.Sprintf(zshCompletionTemplate, app.Name), nil
+	case "fish":
+		return app.ToFishCompletion()
+	case "powershell":
+		return fmt.Sprintf(powershellCompletionTemplate, app.Name), nil
+	}
+	return "", fmt.Errorf("unsupported shell %q, expected one of bash, zsh, fish, powershell", shell)
+}
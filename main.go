@@ -97,6 +97,7 @@ func newApp() *cli.App {
 				Email: "github.com/prongbang",
 			},
 		},
+		EnableBashCompletion: true,
 		Commands: []*cli.Command{
 			{
 				Name:  "grpc",
@@ -170,6 +171,23 @@ func newApp() *cli.App {
 					})
 				},
 			},
+			{
+				Name:      "completions",
+				Usage:     "Generate a shell completion script",
+				ArgsUsage: "bash|zsh|fish|powershell",
+				Action: func(c *cli.Context) error {
+					shell := c.Args().First()
+					if shell == "" {
+						return cli.ShowSubcommandHelp(c)
+					}
+					script, err := completionScript(c.App, shell)
+					if err != nil {
+						return err
+					}
+					_, err = fmt.Fprint(c.App.Writer, script)
+					return err
+				},
+			},
 		},
 		Flags: []cli.Flag{
 			&cli.StringFlag{
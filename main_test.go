@@ -5,6 +5,7 @@ import (
 	"encoding/json"
 	"os"
 	"path/filepath"
+	"strings"
 	"testing"
 )
 
@@ -86,12 +87,69 @@ func TestMainHelpPaths(t *testing.T) {
 		{"codegen", "grpc", "init", "-h"},
 		{"codegen", "grpc", "server", "-h"},
 		{"codegen", "grpc", "client", "-h"},
+		{"codegen", "completions"},
 	} {
 		os.Args = args
 		main()
 	}
 }
 
+func TestCompletionsCommand(t *testing.T) {
+	for shell, want := range map[string]string{
+		"bash":       "complete -o bashdefault -o default -o nospace -F _codegen_bash_autocomplete codegen",
+		"zsh":        "compdef _codegen_zsh_autocomplete codegen",
+		"fish":       "complete -c codegen",
+		"powershell": "Register-ArgumentCompleter -Native -CommandName 'codegen'",
+	} {
+		output := captureStdout(t, func() error {
+			return newApp().Run([]string{"codegen", "completions", shell})
+		})
+		if !strings.Contains(output, want) {
+			t.Fatalf("expected %s completion to contain %q, got: %s", shell, want, output)
+		}
+	}
+}
+
+func TestCompletionsPowershellSlicesLineAtCursor(t *testing.T) {
+	output := captureStdout(t, func() error {
+		return newApp().Run([]string{"codegen", "completions", "powershell"})
+	})
+	for _, want := range []string{
+		"if ($line.Length -gt $pos) {",
+		"$line = $line.TrimEnd()",
+		`Invoke-Expression "$line --generate-bash-completion"`,
+	} {
+		if !strings.Contains(output, want) {
+			t.Fatalf("expected powershell completion to contain %q, got: %s", want, output)
+		}
+	}
+}
+
+func TestGenerateBashCompletionListsCommands(t *testing.T) {
+	output := captureStdout(t, func() error {
+		return newApp().Run([]string{"codegen", "--generate-bash-completion"})
+	})
+	names := strings.Fields(output)
+	for _, want := range []string{"grpc", "openapi", "completions"} {
+		found := false
+		for _, name := range names {
+			if name == want {
+				found = true
+				break
+			}
+		}
+		if !found {
+			t.Fatalf("expected %s in completion output, got: %s", want, output)
+		}
+	}
+}
+
+func TestCompletionsCommandUnsupportedShell(t *testing.T) {
+	if err := newApp().Run([]string{"codegen", "completions", "tcsh"}); err == nil {
+		t.Fatal("expected error for unsupported shell")
+	}
+}
+
 func TestNewGRPCGeneratorFactory(t *testing.T) {
 	if gen := newGRPCGenerator(); gen == nil {
 		t.Fatal("expected grpc generator")
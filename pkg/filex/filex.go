@@ -4,7 +4,10 @@
 package filex
 
 import (
+	"math/rand"
 	"os"
+	"path/filepath"
+	"strconv"
 )
 
 // FileX is the interface
@@ -45,8 +48,63 @@ func (f *fileX) EnsureDir(dir string) error {
 	return err
 }
 
+// WriteFile writes data to a temp file in the same directory and renames it over filename,
+// so an interrupted write never leaves a truncated file behind
 func (f *fileX) WriteFile(filename string, data []byte) error {
-	return os.WriteFile(filename, data, 0755)
+	perm := os.FileMode(0755)
+	info, statErr := os.Stat(filename)
+	if statErr != nil && !os.IsNotExist(statErr) {
+		return statErr
+	}
+	if statErr == nil {
+		perm = info.Mode().Perm()
+		// Write through a symlink to its target instead of replacing the link
+		resolved, err := filepath.EvalSymlinks(filename)
+		if err != nil {
+			return err
+		}
+		filename = resolved
+	}
+
+	tmp, err := createTemp(filepath.Dir(filename), filepath.Base(filename), perm)
+	if err != nil {
+		return err
+	}
+	tmpName := tmp.Name()
+	defer func() { _ = os.Remove(tmpName) }()
+
+	if _, err := tmp.Write(data); err != nil {
+		_ = tmp.Close()
+		return err
+	}
+	// Keep the exact mode of an existing file, the umask may have masked it on create
+	if statErr == nil {
+		if err := tmp.Chmod(perm); err != nil {
+			_ = tmp.Close()
+			return err
+		}
+	}
+	if err := tmp.Sync(); err != nil {
+		_ = tmp.Close()
+		return err
+	}
+	if err := tmp.Close(); err != nil {
+		return err
+	}
+	return os.Rename(tmpName, filename)
+}
+
+// createTemp opens a new hidden temp file next to name with perm, so the umask applies like os.WriteFile
+func createTemp(dir, name string, perm os.FileMode) (*os.File, error) {
+	for i := 0; i < 10000; i++ {
+		tmpName := filepath.Join(dir, "."+name+"."+strconv.FormatUint(uint64(rand.Uint32()), 10)+".tmp")
+		tmp, err := os.OpenFile(tmpName, os.O_RDWR|os.O_CREATE|os.O_EXCL, perm)
+		if os.IsExist(err) {
+			continue
+		}
+		return tmp, err
+	}
+	return nil, &os.PathError{Op: "createtemp", Path: filepath.Join(dir, "."+name+".*.tmp"), Err: os.ErrExist}
 }
 
 func (f *fileX) ReadFile(filename string) string {
@@ -56,3 +56,101 @@ func TestFileXOperations(t *testing.T) {
 		t.Fatalf("unexpected working dir: %s", gotwd)
 	}
 }
+
+func TestFileXWriteFileReplacesContent(t *testing.T) {
+	fx := NewFileX()
+	dir := t.TempDir()
+	file := filepath.Join(dir, "main.go")
+
+	if err := fx.WriteFile(file, []byte("package old")); err != nil {
+		t.Fatal(err)
+	}
+	if err := fx.WriteFile(file, []byte("package main")); err != nil {
+		t.Fatal(err)
+	}
+	if got := fx.ReadFile(file); got != "package main" {
+		t.Fatalf("unexpected file contents: %q", got)
+	}
+
+	entries, err := os.ReadDir(dir)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(entries) != 1 || entries[0].Name() != "main.go" {
+		t.Fatalf("expected only main.go to remain, got %v", entries)
+	}
+
+	info, err := os.Stat(file)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if mode := info.Mode().Perm(); mode&^0755 != 0 {
+		t.Fatalf("expected new file mode within 0755, got %v", mode)
+	}
+}
+
+func TestFileXWriteFileKeepsExistingMode(t *testing.T) {
+	fx := NewFileX()
+	file := filepath.Join(t.TempDir(), "wire.go")
+
+	if err := os.WriteFile(file, []byte("package old"), 0o644); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.Chmod(file, 0o644); err != nil {
+		t.Fatal(err)
+	}
+	if err := fx.WriteFile(file, []byte("package main")); err != nil {
+		t.Fatal(err)
+	}
+
+	info, err := os.Stat(file)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if mode := info.Mode().Perm(); mode != 0o644 {
+		t.Fatalf("expected mode 0644 to be kept, got %v", mode)
+	}
+	if got := fx.ReadFile(file); got != "package main" {
+		t.Fatalf("unexpected file contents: %q", got)
+	}
+}
+
+func TestFileXWriteFileMissingDir(t *testing.T) {
+	fx := NewFileX()
+	file := filepath.Join(t.TempDir(), "missing", "main.go")
+
+	if err := fx.WriteFile(file, []byte("package main")); err == nil {
+		t.Fatal("expected error when parent dir does not exist")
+	}
+	if fx.IsExist(file) {
+		t.Fatalf("expected no file to be written: %s", file)
+	}
+}
+
+func TestFileXWriteFileThroughSymlink(t *testing.T) {
+	fx := NewFileX()
+	dir := t.TempDir()
+	target := filepath.Join(dir, "target.go")
+	link := filepath.Join(dir, "link.go")
+
+	if err := os.WriteFile(target, []byte("package old"), 0o644); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.Symlink(target, link); err != nil {
+		t.Skip("symlinks not supported:", err)
+	}
+	if err := fx.WriteFile(link, []byte("package main")); err != nil {
+		t.Fatal(err)
+	}
+
+	info, err := os.Lstat(link)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if info.Mode()&os.ModeSymlink == 0 {
+		t.Fatalf("expected %s to remain a symlink", link)
+	}
+	if got := fx.ReadFile(target); got != "package main" {
+		t.Fatalf("unexpected target contents: %q", got)
+	}
+}